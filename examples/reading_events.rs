@@ -2,6 +2,7 @@
 #![allow(unused_imports)]
 #![allow(unused_results)]
 #![allow(unused_variables)]
+#![allow(unused_must_use)]
 
 use eventstore::{
     All, Client, Credentials, EventData, ExpectedRevision, Position, ReadAllOptions, ReadResult,
@@ -115,6 +116,24 @@ pub async fn read_stream_backwards(client: &Client) -> Result<()> {
     Ok(())
 }
 
+pub async fn read_last_event(client: &Client) -> Result<()> {
+    // region read-last-event
+    let event = client
+        .read_last_event("some-stream", &Default::default())
+        .await?;
+
+    if let Some(event) = event {
+        println!(
+            "Last event> {:?}, revision: {}",
+            event.get_original_event().event_type,
+            event.get_original_event().revision
+        );
+    }
+    // endregion read-last-event
+
+    Ok(())
+}
+
 pub async fn read_from_all_stream(client: &Client) -> Result<()> {
     // region read-from-all-stream
     let options = ReadAllOptions::default()
@@ -2,6 +2,8 @@
 #![allow(unused_imports)]
 #![allow(unused_results)]
 #![allow(unused_variables)]
+#![allow(unused_must_use)]
+#![allow(dead_code)]
 
 use eventstore::{
     Client, Credentials, EventData, ExpectedRevision, ReadResult, SubEvent, SubscribeToAllOptions,
@@ -2,6 +2,7 @@
 #![allow(unused_imports)]
 #![allow(unused_results)]
 #![allow(unused_variables)]
+#![allow(unused_must_use)]
 
 use eventstore::{
     Client, Credentials, EventData, ExpectedRevision, Position, ReadResult, RetryOptions,
@@ -3,12 +3,14 @@ use crate::options::batch_append::BatchAppendOptions;
 use crate::options::persistent_subscription::PersistentSubscriptionOptions;
 use crate::options::read_all::ReadAllOptions;
 use crate::options::read_stream::ReadStreamOptions;
+use crate::options::retry::RetryOptions;
 use crate::options::subscribe_to_stream::SubscribeToStreamOptions;
 use crate::{
-    commands, ConnectToPersistentSubscription, DeletePersistentSubscriptionOptions,
-    DeleteStreamOptions, Position, ReadResult, StreamMetadata, SubEvent, SubscribeToAllOptions,
-    SubscriptionRead, SubscriptionWrite, ToCount, TombstoneStreamOptions, WriteResult,
-    WrongExpectedVersion,
+    commands, ChunkedWriteFailure, ChunkedWriteResult, ConnectToPersistentSubscription,
+    DeletePersistentSubscriptionOptions, DeleteStreamOptions, ExpectedRevision, Position,
+    ReadResult, ResolvedEvent, StreamMetadata, StreamMetadataResult, StreamPosition, StreamState,
+    SubEvent, SubscribeToAllOptions, SubscriptionRead, SubscriptionWrite, ToCount,
+    TombstoneStreamOptions, VersionedMetadata, WriteResult, WrongExpectedVersion,
 };
 use crate::{
     grpc::{ClientSettings, GrpcClient},
@@ -19,7 +21,7 @@ use crate::{
     EventData,
 };
 use futures::stream::BoxStream;
-use futures::TryStreamExt;
+use futures::{Future, TryStreamExt};
 
 /// Represents a client to a single node. `Client` maintains a full duplex
 /// communication to EventStoreDB.
@@ -29,15 +31,14 @@ use futures::TryStreamExt;
 #[derive(Clone)]
 pub struct Client {
     client: GrpcClient,
-    settings: ClientSettings,
 }
 
 impl Client {
     /// Creates a gRPC client to an EventStoreDB database.
     pub async fn new(settings: ClientSettings) -> Result<Self, Box<dyn std::error::Error>> {
-        let client = GrpcClient::create(settings.clone()).await?;
+        let client = GrpcClient::create(settings).await?;
 
-        Ok(Client { client, settings })
+        Ok(Client { client })
     }
 
     /// Sends events to a given stream.
@@ -54,6 +55,77 @@ impl Client {
         commands::append_to_stream(&self.client, stream_name, options, events.into_events()).await
     }
 
+    /// Sends events to several streams at once. The appends are sent concurrently over the
+    /// same connection, each with its own options, and their results are returned in the same
+    /// order as `appends`. Each append is atomic on its own, but not in relation to the others.
+    pub async fn append_to_streams<StreamName>(
+        &self,
+        appends: Vec<(StreamName, AppendToStreamOptions, Vec<EventData>)>,
+    ) -> Vec<crate::Result<Result<WriteResult, WrongExpectedVersion>>>
+    where
+        StreamName: AsRef<str>,
+    {
+        let appends = appends
+            .into_iter()
+            .map(|(stream_name, options, events)| async move {
+                self.append_to_stream(stream_name, &options, events).await
+            });
+
+        futures::future::join_all(appends).await
+    }
+
+    /// Sends events to a given stream, split into several appends so none of them exceeds
+    /// `max_append_size` bytes (the server rejects appends bigger than its `MaxAppendSize`,
+    /// 1MiB by default). The appends run sequentially and each one after the first expects
+    /// the revision left by the previous one. Unlike `append_to_stream`, the whole operation
+    /// is not atomic: if a later append fails, the previous ones stay committed.
+    ///
+    /// Returns the results of the committed appends, in order, along with the failure that
+    /// stopped the remaining ones if any. When `events` is empty, a single empty append is
+    /// sent so the expected revision is still checked.
+    pub async fn append_to_stream_in_chunks<StreamName>(
+        &self,
+        stream_name: StreamName,
+        options: &AppendToStreamOptions,
+        events: Vec<EventData>,
+        max_append_size: usize,
+    ) -> ChunkedWriteResult
+    where
+        StreamName: AsRef<str>,
+    {
+        let stream_name = stream_name.as_ref();
+        let mut options = options.clone();
+        let mut chunks = crate::types::chunk_events(events, max_append_size);
+        let mut result = ChunkedWriteResult {
+            committed: Vec::new(),
+            failure: None,
+        };
+
+        if chunks.is_empty() {
+            chunks.push(Vec::new());
+        }
+
+        for chunk in chunks {
+            let failure = match self.append_to_stream(stream_name, &options, chunk).await {
+                Ok(Ok(write_result)) => {
+                    options = options.expected_revision(ExpectedRevision::Exact(
+                        write_result.next_expected_version,
+                    ));
+                    result.committed.push(write_result);
+                    continue;
+                }
+
+                Ok(Err(e)) => ChunkedWriteFailure::WrongExpectedVersion(e),
+                Err(e) => ChunkedWriteFailure::Error(e),
+            };
+
+            result.failure = Some(failure);
+            break;
+        }
+
+        result
+    }
+
     // Sets a stream metadata.
     pub async fn set_stream_metadata<StreamName>(
         &self,
@@ -81,6 +153,10 @@ impl Client {
 
     /// Reads events from a given stream. The reading can be done forward and
     /// backward.
+    ///
+    /// See [`read_last_event`] to read only the last event of the stream.
+    ///
+    /// [`read_last_event`]: #method.read_last_event
     pub async fn read_stream<StreamName, Count>(
         &self,
         stream_name: StreamName,
@@ -91,9 +167,11 @@ impl Client {
         StreamName: AsRef<str>,
         Count: ToCount<'static>,
     {
-        let result =
+        let stream_name = stream_name.as_ref();
+        let result = with_retry(options.retry, || {
             commands::read_stream(&self.client, options, stream_name, count.to_count() as u64)
-                .await?;
+        })
+        .await?;
 
         match result {
             ReadResult::Ok(stream) => {
@@ -116,31 +194,87 @@ impl Client {
     where
         Count: ToCount<'static>,
     {
-        let stream = commands::read_all(&self.client, options, count.to_count() as u64).await?;
+        let stream = with_retry(options.retry, || {
+            commands::read_all(&self.client, options, count.to_count() as u64)
+        })
+        .await?;
 
         count.select(stream).await
     }
 
-    /// Reads a stream metadata.
+    /// Reads a stream metadata. Along with the metadata, it returns the
+    /// metastream revision, which can be used as expected revision when
+    /// updating the metadata with [`set_stream_metadata`].
+    ///
+    /// [`set_stream_metadata`]: #method.set_stream_metadata
     pub async fn get_stream_metadata<StreamName>(
         &self,
         stream_name: StreamName,
         options: &ReadStreamOptions,
-    ) -> crate::Result<StreamMetadata>
+    ) -> crate::Result<StreamMetadataResult>
     where
         StreamName: AsRef<str>,
     {
+        let stream = stream_name.as_ref().to_string();
         let result = self
-            .read_stream(format!("$${}", stream_name.as_ref()), options, Single)
-            .await?;
+            .read_stream(format!("$${}", stream), options, Single)
+            .await;
 
         match result {
-            ReadResult::StreamNotFound(_) => Ok(StreamMetadata::default()),
-            ReadResult::Ok(event) => event
-                .expect("to be defined")
-                .get_original_event()
-                .as_json::<StreamMetadata>()
-                .map_err(|e| crate::Error::InternalParsingError(e.to_string())),
+            Err(crate::Error::StreamDeleted(_)) => Ok(StreamMetadataResult::Deleted { stream }),
+            Err(e) => Err(e),
+            Ok(ReadResult::StreamNotFound(_)) => Ok(StreamMetadataResult::NotFound { stream }),
+            Ok(ReadResult::Ok(event)) => {
+                let event = event.expect("to be defined");
+                let event = event.get_original_event();
+                let metadata = event
+                    .as_json::<StreamMetadata>()
+                    .map_err(|e| crate::Error::InternalParsingError(e.to_string()))?;
+
+                Ok(StreamMetadataResult::Success(Box::new(VersionedMetadata {
+                    stream,
+                    version: event.revision,
+                    metadata,
+                })))
+            }
+        }
+    }
+
+    /// Reads the last event of a stream, which carries the stream's current
+    /// revision. Returns `None` when the stream doesn't exist or is empty.
+    /// `options` position and direction are ignored.
+    pub async fn read_last_event<StreamName>(
+        &self,
+        stream_name: StreamName,
+        options: &ReadStreamOptions,
+    ) -> crate::Result<Option<ResolvedEvent>>
+    where
+        StreamName: AsRef<str>,
+    {
+        let options = options.clone().position(StreamPosition::End);
+
+        match self.read_stream(stream_name, &options, Single).await? {
+            ReadResult::Ok(event) => Ok(event),
+            ReadResult::StreamNotFound(_) => Ok(None),
+        }
+    }
+
+    /// Tells whether a stream exists and, if so, what its current revision
+    /// is. It helps deciding between `ExpectedRevision::NoStream` and
+    /// `ExpectedRevision::Exact` before an append.
+    pub async fn stream_state<StreamName>(
+        &self,
+        stream_name: StreamName,
+        options: &ReadStreamOptions,
+    ) -> crate::Result<StreamState>
+    where
+        StreamName: AsRef<str>,
+    {
+        match self.read_last_event(stream_name, options).await {
+            Err(crate::Error::StreamDeleted(_)) => Ok(StreamState::Deleted),
+            Err(e) => Err(e),
+            Ok(None) => Ok(StreamState::NoStream),
+            Ok(Some(event)) => Ok(StreamState::Exists(event.get_original_event().revision)),
         }
     }
 
@@ -406,3 +540,70 @@ impl Client {
         .await
     }
 }
+
+/// Runs the given command, retrying it according to the retry options if any. Errors that
+/// won't go away by trying again, like an access denied, are returned right away.
+async fn with_retry<F, Fut, A>(retry: Option<RetryOptions>, action: F) -> crate::Result<A>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = crate::Result<A>>,
+{
+    let retry = match retry {
+        None => return action().await,
+        Some(retry) => retry,
+    };
+
+    let mut attempt_count = 1usize;
+
+    loop {
+        match action().await {
+            Err(e) => {
+                let retryable = !matches!(
+                    e,
+                    crate::Error::AccessDenied
+                        | crate::Error::ResourceNotFound
+                        | crate::Error::StreamDeleted(_)
+                        | crate::Error::InternalParsingError(_)
+                );
+
+                if !retryable || attempt_count >= retry.limit {
+                    return Err(e);
+                }
+
+                error!(
+                    "Command: attempt ({}/{}) failure, cause: {}",
+                    attempt_count, retry.limit, e
+                );
+                attempt_count += 1;
+                tokio::time::sleep(retry.delay).await;
+            }
+
+            ok => return ok,
+        }
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_client_side_deadline_exceeded() {
+    use std::time::Duration;
+
+    // Accepts connections but never answers, so only the deadline can end the call.
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let settings = format!(
+        "esdb://127.0.0.1:{}?tls=false",
+        listener.local_addr().unwrap().port()
+    )
+    .parse::<ClientSettings>()
+    .unwrap();
+
+    let client = Client::new(settings).await.unwrap();
+    let options = DeleteStreamOptions::default().deadline(Duration::from_millis(200));
+    let result = tokio::time::timeout(
+        Duration::from_secs(5),
+        client.delete_stream("foobar", &options),
+    )
+    .await
+    .expect("The deadline wasn't applied");
+
+    assert!(matches!(result, Err(crate::Error::DeadlineExceeded)));
+}
@@ -27,6 +27,8 @@ use crate::{
     SubscribeToAllOptions, SubscriptionFilter, SystemConsumerStrategy, TombstoneStreamOptions,
 };
 use futures::stream::BoxStream;
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
 use tonic::Request;
 
 fn raw_uuid_to_uuid(src: Uuid) -> uuid::Uuid {
@@ -99,6 +101,15 @@ fn convert_event_data(event: EventData) -> streams::AppendReq {
     }
 }
 
+/// The server stores the event creation date in the `created` metadata, in ticks since the Unix
+/// epoch.
+fn created_from_metadata(metadata: &HashMap<String, String>) -> Option<SystemTime> {
+    metadata
+        .get("created")
+        .and_then(|ticks| ticks.parse().ok())
+        .and_then(crate::types::from_ticks_since_epoch)
+}
+
 fn convert_proto_recorded_event(
     event: streams::read_resp::read_event::RecordedEvent,
 ) -> RecordedEvent {
@@ -124,6 +135,8 @@ fn convert_proto_recorded_event(
         false
     };
 
+    let created = created_from_metadata(&event.metadata);
+
     let stream_id = String::from_utf8(
         event
             .stream_identifier
@@ -138,6 +151,7 @@ fn convert_proto_recorded_event(
         position,
         event_type,
         is_json,
+        created,
         metadata: event.metadata,
         custom_metadata: event.custom_metadata.into(),
         data: event.data.into(),
@@ -169,6 +183,8 @@ fn convert_persistent_proto_recorded_event(
         false
     };
 
+    let created = created_from_metadata(&event.metadata);
+
     let stream_id = String::from_utf8(
         event
             .stream_identifier
@@ -184,6 +200,7 @@ fn convert_persistent_proto_recorded_event(
         position,
         event_type,
         is_json,
+        created,
         metadata: event.metadata,
         custom_metadata: event.custom_metadata.into(),
         data: event.data.into(),
@@ -318,10 +335,10 @@ pub(crate) fn configure_auth_req<A>(req: &mut Request<A>, creds_opt: Option<Cred
     use tonic::metadata::MetadataValue;
 
     if let Some(creds) = creds_opt {
-        let login = String::from_utf8_lossy(&*creds.login).into_owned();
-        let password = String::from_utf8_lossy(&*creds.password).into_owned();
+        let login = String::from_utf8_lossy(&creds.login).into_owned();
+        let password = String::from_utf8_lossy(&creds.password).into_owned();
 
-        let basic_auth_string = base64::encode(&format!("{}:{}", login, password));
+        let basic_auth_string = base64::encode(format!("{}:{}", login, password));
         let basic_auth = format!("Basic {}", basic_auth_string);
         let header_value = MetadataValue::from_str(basic_auth.as_str())
             .expect("Auth header value should be valid metadata header value");
@@ -329,6 +346,21 @@ pub(crate) fn configure_auth_req<A>(req: &mut Request<A>, creds_opt: Option<Cred
         req.metadata_mut().insert("authorization", header_value);
     }
 }
+pub(crate) fn configure_deadline<A>(req: &mut Request<A>, deadline: Option<Duration>) {
+    if let Some(deadline) = deadline {
+        req.set_timeout(deadline);
+    }
+}
+
+pub(crate) fn configure_requires_leader<A>(req: &mut Request<A>, requires_leader: bool) {
+    use tonic::metadata::MetadataValue;
+
+    if requires_leader {
+        req.metadata_mut()
+            .insert("requires-leader", MetadataValue::from_static("true"));
+    }
+}
+
 pub fn filter_into_proto(filter: SubscriptionFilter) -> streams::read_req::options::FilterOptions {
     use options::filter_options::{Expression, Filter, Window};
     use streams::read_req::options::{self, FilterOptions};
@@ -339,7 +371,7 @@ pub fn filter_into_proto(filter: SubscriptionFilter) -> streams::read_req::optio
     };
 
     let expr = Expression {
-        regex: filter.regex.unwrap_or_else(|| "".to_string()),
+        regex: filter.regex.unwrap_or_default(),
         prefix: filter.prefixes,
     };
 
@@ -367,9 +399,18 @@ where
     S: AsRef<str>,
     Events: Stream<Item = EventData> + Send + Sync + 'static,
 {
+    use streams::append_req::options::ExpectedStreamRevision;
     use streams::append_req::{self, Content};
     use streams::AppendReq;
 
+    let expected_stream_revision = match options.version {
+        ExpectedRevision::Any => ExpectedStreamRevision::Any(Empty {}),
+        ExpectedRevision::NoStream => ExpectedStreamRevision::NoStream(Empty {}),
+        ExpectedRevision::StreamExists => ExpectedStreamRevision::StreamExists(Empty {}),
+        ExpectedRevision::Exact(rev) => ExpectedStreamRevision::Revision(rev),
+    };
+
+    let expected_stream_revision = Some(expected_stream_revision);
     let stream = stream.as_ref().to_string();
 
     connection.execute(move |channel| async move {
@@ -378,7 +419,7 @@ where
         });
         let header = Content::Options(append_req::Options {
             stream_identifier,
-            expected_stream_revision: Some(options.version.clone()),
+            expected_stream_revision: expected_stream_revision.clone(),
         });
         let header = AppendReq {
             content: Some(header),
@@ -389,8 +430,13 @@ where
         let mut req = Request::new(payload);
 
         let credentials = options.credentials.clone().or_else(|| connection.default_credentials());
+        let requires_leader = options
+            .requires_leader
+            .unwrap_or_else(|| connection.requires_leader());
 
         configure_auth_req(&mut req, credentials);
+        configure_deadline(&mut req, options.deadline);
+        configure_requires_leader(&mut req, requires_leader);
 
         let mut client = StreamsClient::new(channel.channel);
         let resp = client.append(req).await?.into_inner();
@@ -442,7 +488,7 @@ where
     }).await
 }
 
-pub async fn batch_append<'a>(
+pub async fn batch_append(
     connection: &GrpcClient,
     options: &BatchAppendOptions,
 ) -> crate::Result<BatchAppendClient> {
@@ -468,8 +514,12 @@ pub async fn batch_append<'a>(
         .as_ref()
         .cloned()
         .or_else(|| connection.default_credentials());
+    let requires_leader = options
+        .requires_leader
+        .unwrap_or_else(|| connection.requires_leader());
+    let deadline = options.deadline;
 
-    let receiver = receiver.map(|req| {
+    let receiver = receiver.map(move |req| {
         let correlation_id = shared::uuid::Value::String(req.id.to_string());
         let correlation_id = Some(Uuid {
             value: Some(correlation_id),
@@ -493,9 +543,11 @@ pub async fn batch_append<'a>(
             .map(convert_event_data_to_batch_proposed_message)
             .collect();
 
+        // Each batch gets its own deadline, as the session can stay open indefinitely.
+        let deadline = deadline.map(|deadline| (std::time::SystemTime::now() + deadline).into());
         let options = Some(Options {
             stream_identifier,
-            deadline: None,
+            deadline,
             expected_stream_position,
         });
 
@@ -512,6 +564,7 @@ pub async fn batch_append<'a>(
             .execute(move |handle| async move {
                 let mut req = Request::new(receiver);
                 configure_auth_req(&mut req, credentials);
+                configure_requires_leader(&mut req, requires_leader);
                 let mut client = StreamsClient::new(handle.channel.clone());
 
                 let resp = client.batch_append(req).await?;
@@ -567,7 +620,7 @@ pub async fn batch_append<'a>(
                     let message = code.message;
                     let code = tonic::Code::from(code.code);
                     let status = tonic::Status::new(code, message);
-                    let err = crate::Error::Grpc(status);
+                    let err = crate::Error::from_grpc(status);
 
                     Err(err)
                 }
@@ -661,6 +714,7 @@ pub async fn read_stream<'a, S: AsRef<str>>(
         .credentials
         .clone()
         .or_else(|| connection.default_credentials());
+    let deadline = options.deadline;
 
     let options = Options {
         stream_option: Some(StreamOption::Stream(stream_options)),
@@ -678,6 +732,7 @@ pub async fn read_stream<'a, S: AsRef<str>>(
     let mut req = Request::new(req);
 
     configure_auth_req(&mut req, credentials);
+    configure_deadline(&mut req, deadline);
 
     connection
         .execute(|channel| async {
@@ -784,6 +839,7 @@ pub async fn read_all<'a>(
         .credentials
         .clone()
         .or_else(|| connection.default_credentials());
+    let deadline = options.deadline;
 
     let options = Options {
         stream_option: Some(StreamOption::All(stream_options)),
@@ -801,6 +857,7 @@ pub async fn read_all<'a>(
     let mut req = Request::new(req);
 
     configure_auth_req(&mut req, credentials);
+    configure_deadline(&mut req, deadline);
 
     connection
         .execute(|channel| async {
@@ -850,6 +907,10 @@ pub async fn delete_stream<S: AsRef<str>>(
         .credentials
         .clone()
         .or_else(|| connection.default_credentials());
+    let deadline = options.deadline;
+    let requires_leader = options
+        .requires_leader
+        .unwrap_or_else(|| connection.requires_leader());
 
     use streams::delete_req::options::ExpectedStreamRevision;
     use streams::delete_req::Options;
@@ -876,6 +937,8 @@ pub async fn delete_stream<S: AsRef<str>>(
     });
 
     configure_auth_req(&mut req, credentials);
+    configure_deadline(&mut req, deadline);
+    configure_requires_leader(&mut req, requires_leader);
 
     connection
         .execute(|channel| async {
@@ -912,6 +975,10 @@ pub async fn tombstone_stream<S: AsRef<str>>(
         .credentials
         .clone()
         .or_else(|| connection.default_credentials());
+    let deadline = options.deadline;
+    let requires_leader = options
+        .requires_leader
+        .unwrap_or_else(|| connection.requires_leader());
 
     use streams::tombstone_req::options::ExpectedStreamRevision;
     use streams::tombstone_req::Options;
@@ -938,6 +1005,8 @@ pub async fn tombstone_stream<S: AsRef<str>>(
     });
 
     configure_auth_req(&mut req, credentials);
+    configure_deadline(&mut req, deadline);
+    configure_requires_leader(&mut req, requires_leader);
 
     connection
         .execute(|channel| async {
@@ -1496,6 +1565,16 @@ impl SubscriptionWrite {
         Ok(())
     }
 
+    pub async fn nack_event(
+        &mut self,
+        event: ResolvedEvent,
+        action: NakAction,
+        reason: String,
+    ) -> Result<(), tonic::Status> {
+        self.nack(vec![event.get_original_event().id], action, reason)
+            .await
+    }
+
     pub async fn nack<I>(
         &mut self,
         event_ids: I,
@@ -1503,13 +1582,13 @@ impl SubscriptionWrite {
         reason: String,
     ) -> Result<(), tonic::Status>
     where
-        I: Iterator<Item = uuid::Uuid>,
+        I: IntoIterator<Item = uuid::Uuid>,
     {
         use futures::sink::SinkExt;
         use persistent::read_req::{Content, Nack};
         use persistent::ReadReq;
 
-        let ids = event_ids.map(to_proto_uuid).collect();
+        let ids = event_ids.into_iter().map(to_proto_uuid).collect();
 
         let action = match action {
             NakAction::Unknown => 0,
@@ -17,3 +17,7 @@ pub mod projections {
 pub mod streams {
     pub use super::super::generated::streams::*;
 }
+
+pub mod users {
+    pub use super::super::generated::users::*;
+}
@@ -4,5 +4,6 @@ pub mod gossip;
 pub mod persistent;
 pub mod projections;
 pub mod streams;
+pub mod users;
 
 pub use client::*;
@@ -1,3 +1,3 @@
 pub mod client;
-#[allow(clippy::all)]
+#[allow(clippy::all, dead_code)]
 pub mod generated;
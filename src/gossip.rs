@@ -76,6 +76,7 @@ impl Gossip {
     }
 }
 
+#[allow(dead_code)]
 #[derive(Debug)]
 pub struct MemberInfo {
     pub instance_id: Uuid,
@@ -106,6 +107,7 @@ pub enum VNodeState {
 }
 
 impl VNodeState {
+    #[allow(clippy::result_large_err)]
     pub fn from_i32(value: i32) -> Result<Self, Status> {
         match value {
             0 => Ok(VNodeState::Initializing),
@@ -71,6 +71,7 @@ mod options;
 mod private;
 mod projection_client;
 mod types;
+mod user_management_client;
 
 pub(crate) mod google {
     pub mod rpc {
@@ -78,12 +79,20 @@ pub(crate) mod google {
     }
 }
 
+/// Version of this client library, reported to the server in the `user-agent` header.
+pub const CLIENT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
 pub use client::Client;
 pub use commands::{SubscriptionRead, SubscriptionWrite};
-pub use grpc::{ClientSettings, ClientSettingsParseError};
+pub use grpc::{
+    ClientSettings, ClientSettingsBuildError, ClientSettingsBuilder, ClientSettingsEnvError,
+    ClientSettingsParseError,
+};
 pub use options::append_to_stream::*;
+pub use options::batch_append::*;
 pub use options::delete_stream::*;
 pub use options::persistent_subscription::*;
+pub use options::projections::*;
 pub use options::read_all::*;
 pub use options::read_stream::*;
 pub use options::retry::*;
@@ -92,14 +101,20 @@ pub use options::subscribe_to_stream::*;
 pub use options::tombstone_stream::*;
 pub use projection_client::*;
 pub use types::*;
+pub use user_management_client::*;
 
 pub mod prelude {
     pub use crate::client::Client;
     pub use crate::commands::{SubscriptionRead, SubscriptionWrite};
-    pub use crate::grpc::{ClientSettings, ClientSettingsParseError};
+    pub use crate::grpc::{
+        ClientSettings, ClientSettingsBuildError, ClientSettingsBuilder, ClientSettingsEnvError,
+        ClientSettingsParseError,
+    };
     pub use crate::options::append_to_stream::*;
+    pub use crate::options::batch_append::*;
     pub use crate::options::delete_stream::*;
     pub use crate::options::persistent_subscription::*;
+    pub use crate::options::projections::*;
     pub use crate::options::read_all::*;
     pub use crate::options::read_stream::*;
     pub use crate::options::retry::*;
@@ -108,4 +123,5 @@ pub mod prelude {
     pub use crate::options::tombstone_stream::*;
     pub use crate::projection_client::*;
     pub use crate::types::*;
+    pub use crate::user_management_client::*;
 }
@@ -1,23 +1,26 @@
-use crate::event_store::client::shared::Empty;
-use crate::event_store::client::streams::append_req::options::ExpectedStreamRevision;
 use crate::private::Sealed;
 use crate::{Credentials, EventData, ExpectedRevision};
 use futures::future::Ready;
 use futures::stream::{Iter, Once};
 use futures::Stream;
+use std::time::Duration;
 
 #[derive(Clone)]
 /// Options of the append to stream command.
 pub struct AppendToStreamOptions {
-    pub(crate) version: ExpectedStreamRevision,
+    pub(crate) version: ExpectedRevision,
     pub(crate) credentials: Option<Credentials>,
+    pub(crate) deadline: Option<Duration>,
+    pub(crate) requires_leader: Option<bool>,
 }
 
 impl Default for AppendToStreamOptions {
     fn default() -> Self {
         Self {
-            version: ExpectedStreamRevision::Any(Empty {}),
+            version: ExpectedRevision::Any,
             credentials: None,
+            deadline: None,
+            requires_leader: None,
         }
     }
 }
@@ -31,16 +34,27 @@ impl AppendToStreamOptions {
         }
     }
 
+    /// Fails the command with `Error::DeadlineExceeded` if it doesn't complete within the given
+    /// duration. Default: no deadline.
+    pub fn deadline(self, deadline: Duration) -> Self {
+        Self {
+            deadline: Some(deadline),
+            ..self
+        }
+    }
+
+    /// When `true`, a follower node rejects the command instead of forwarding it to the leader.
+    /// Default: `true` when the connection's node preference is `NodePreference::Leader`.
+    pub fn requires_leader(self, requires_leader: bool) -> Self {
+        Self {
+            requires_leader: Some(requires_leader),
+            ..self
+        }
+    }
+
     /// Asks the server to check that the stream receiving the event is at
     /// the given expected version. Default: `ExpectedVersion::Any`.
     pub fn expected_revision(self, version: ExpectedRevision) -> Self {
-        let version = match version {
-            ExpectedRevision::Any => ExpectedStreamRevision::Any(Empty {}),
-            ExpectedRevision::StreamExists => ExpectedStreamRevision::StreamExists(Empty {}),
-            ExpectedRevision::NoStream => ExpectedStreamRevision::NoStream(Empty {}),
-            ExpectedRevision::Exact(version) => ExpectedStreamRevision::Revision(version),
-        };
-
         Self { version, ..self }
     }
 }
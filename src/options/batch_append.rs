@@ -1,14 +1,12 @@
 use crate::Credentials;
+use std::time::Duration;
 
-#[derive(Clone)]
+#[derive(Clone, Default)]
+/// Options of the batch append command.
 pub struct BatchAppendOptions {
     pub(crate) credentials: Option<Credentials>,
-}
-
-impl Default for BatchAppendOptions {
-    fn default() -> Self {
-        Self { credentials: None }
-    }
+    pub(crate) deadline: Option<Duration>,
+    pub(crate) requires_leader: Option<bool>,
 }
 
 impl BatchAppendOptions {
@@ -16,6 +14,26 @@ impl BatchAppendOptions {
     pub fn authenticated(self, credentials: Credentials) -> Self {
         Self {
             credentials: Some(credentials),
+            ..self
+        }
+    }
+
+    /// Fails each batch with `Error::DeadlineExceeded` if the server doesn't commit it within
+    /// the given duration after it's sent. The batch append session itself stays open.
+    /// Default: no deadline.
+    pub fn deadline(self, deadline: Duration) -> Self {
+        Self {
+            deadline: Some(deadline),
+            ..self
+        }
+    }
+
+    /// When `true`, a follower node rejects the command instead of forwarding it to the leader.
+    /// Default: `true` when the connection's node preference is `NodePreference::Leader`.
+    pub fn requires_leader(self, requires_leader: bool) -> Self {
+        Self {
+            requires_leader: Some(requires_leader),
+            ..self
         }
     }
 }
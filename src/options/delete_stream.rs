@@ -1,10 +1,13 @@
 use crate::{Credentials, ExpectedRevision};
+use std::time::Duration;
 
 #[derive(Clone)]
 /// Options of the delete stream command.
 pub struct DeleteStreamOptions {
     pub(crate) version: ExpectedRevision,
     pub(crate) credentials: Option<Credentials>,
+    pub(crate) deadline: Option<Duration>,
+    pub(crate) requires_leader: Option<bool>,
 }
 
 impl Default for DeleteStreamOptions {
@@ -12,6 +15,8 @@ impl Default for DeleteStreamOptions {
         Self {
             version: ExpectedRevision::Any,
             credentials: None,
+            deadline: None,
+            requires_leader: None,
         }
     }
 }
@@ -25,6 +30,24 @@ impl DeleteStreamOptions {
         }
     }
 
+    /// Fails the command with `Error::DeadlineExceeded` if it doesn't complete within the given
+    /// duration. Default: no deadline.
+    pub fn deadline(self, deadline: Duration) -> Self {
+        Self {
+            deadline: Some(deadline),
+            ..self
+        }
+    }
+
+    /// When `true`, a follower node rejects the command instead of forwarding it to the leader.
+    /// Default: `true` when the connection's node preference is `NodePreference::Leader`.
+    pub fn requires_leader(self, requires_leader: bool) -> Self {
+        Self {
+            requires_leader: Some(requires_leader),
+            ..self
+        }
+    }
+
     /// Asks the server to check that the stream receiving the event is at
     /// the given expected version. Default: `ExpectedVersion::Any`.
     pub fn expected_revision(self, version: ExpectedRevision) -> Self {
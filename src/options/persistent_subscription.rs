@@ -37,17 +37,11 @@ impl PersistentSubscriptionOptions {
     }
 }
 
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub struct DeletePersistentSubscriptionOptions {
     pub(crate) credentials: Option<Credentials>,
 }
 
-impl Default for DeletePersistentSubscriptionOptions {
-    fn default() -> Self {
-        Self { credentials: None }
-    }
-}
-
 impl DeletePersistentSubscriptionOptions {
     /// Performs the command with the given credentials.
     pub fn authenticated(self, value: Credentials) -> Self {
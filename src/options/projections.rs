@@ -31,6 +31,23 @@ impl CreateProjectionOptions {
     }
 }
 
+#[derive(Debug, Clone, Default)]
+pub struct CreateTransientProjectionOptions {
+    pub(crate) credentials: Option<Credentials>,
+}
+
+impl CreateTransientProjectionOptions {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn authenticated(self, value: Credentials) -> Self {
+        Self {
+            credentials: Some(value),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct UpdateProjectionOptions {
     pub(crate) credentials: Option<Credentials>,
@@ -1,11 +1,16 @@
+use crate::options::retry::RetryOptions;
 use crate::{Credentials, Position, ReadDirection, StreamPosition};
+use std::time::Duration;
 
 #[derive(Clone)]
+/// Options of the read $all command.
 pub struct ReadAllOptions {
     pub(crate) credentials: Option<Credentials>,
     pub(crate) direction: ReadDirection,
     pub(crate) position: StreamPosition<Position>,
     pub(crate) resolve_link_tos: bool,
+    pub(crate) deadline: Option<Duration>,
+    pub(crate) retry: Option<RetryOptions>,
 }
 
 impl Default for ReadAllOptions {
@@ -15,6 +20,8 @@ impl Default for ReadAllOptions {
             direction: ReadDirection::Forward,
             position: StreamPosition::Start,
             resolve_link_tos: false,
+            deadline: None,
+            retry: None,
         }
     }
 }
@@ -45,6 +52,15 @@ impl ReadAllOptions {
         }
     }
 
+    /// Fails the command with `Error::DeadlineExceeded` if it doesn't complete within the given
+    /// duration. Default: no deadline.
+    pub fn deadline(self, deadline: Duration) -> Self {
+        Self {
+            deadline: Some(deadline),
+            ..self
+        }
+    }
+
     /// Starts the read at the given position. Default `StreamPosition::Start`
     pub fn position(self, position: StreamPosition<Position>) -> Self {
         match position {
@@ -73,4 +89,14 @@ impl ReadAllOptions {
             ..self
         }
     }
+
+    /// Retries the read when it fails before the server starts sending events back, for
+    /// example because the selected node became unavailable. Authentication and access errors
+    /// aren't retried.
+    pub fn retry_options(self, options: RetryOptions) -> Self {
+        Self {
+            retry: Some(options),
+            ..self
+        }
+    }
 }
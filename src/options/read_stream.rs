@@ -1,4 +1,6 @@
+use crate::options::retry::RetryOptions;
 use crate::{Credentials, ReadDirection, StreamPosition};
+use std::time::Duration;
 
 #[derive(Clone)]
 pub struct ReadStreamOptions {
@@ -6,6 +8,8 @@ pub struct ReadStreamOptions {
     pub(crate) direction: ReadDirection,
     pub(crate) position: StreamPosition<u64>,
     pub(crate) resolve_link_tos: bool,
+    pub(crate) deadline: Option<Duration>,
+    pub(crate) retry: Option<RetryOptions>,
 }
 
 impl Default for ReadStreamOptions {
@@ -15,6 +19,8 @@ impl Default for ReadStreamOptions {
             direction: ReadDirection::Forward,
             position: StreamPosition::Start,
             resolve_link_tos: false,
+            deadline: None,
+            retry: None,
         }
     }
 }
@@ -45,6 +51,15 @@ impl ReadStreamOptions {
         }
     }
 
+    /// Fails the command with `Error::DeadlineExceeded` if it doesn't complete within the given
+    /// duration. Default: no deadline.
+    pub fn deadline(self, deadline: Duration) -> Self {
+        Self {
+            deadline: Some(deadline),
+            ..self
+        }
+    }
+
     /// Starts the read at the given event number. Default `Origin::Start`
     pub fn position(self, position: StreamPosition<u64>) -> Self {
         match position {
@@ -73,4 +88,14 @@ impl ReadStreamOptions {
             ..self
         }
     }
+
+    /// Retries the read when it fails before the server starts sending events back, for
+    /// example because the selected node became unavailable. Authentication and access errors
+    /// aren't retried.
+    pub fn retry_options(self, options: RetryOptions) -> Self {
+        Self {
+            retry: Some(options),
+            ..self
+        }
+    }
 }
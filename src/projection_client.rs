@@ -2,19 +2,30 @@ use crate::event_store::client::projections;
 use crate::event_store::client::shared::Empty;
 use crate::grpc::{ClientSettings, GrpcClient};
 use crate::options::projections::{
-    CreateProjectionOptions, DeleteProjectionOptions, GetResultProjectionOptions,
-    GetStateProjectionOptions, UpdateProjectionOptions,
+    CreateProjectionOptions, CreateTransientProjectionOptions, DeleteProjectionOptions,
+    GetResultProjectionOptions, GetStateProjectionOptions, UpdateProjectionOptions,
 };
 use crate::Credentials;
 use futures::stream::BoxStream;
 use serde::de::DeserializeOwned;
 
-#[allow(dead_code)]
 #[derive(Clone, Debug)]
 pub(crate) enum StatsFor {
     Name(String),
     AllProjections,
     AllContinuous,
+    AllTransient,
+    AllOneTime,
+}
+
+/// Which projections [`ProjectionClient::list_by_mode`] returns.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProjectionListMode {
+    /// Every projection, whatever its mode.
+    All,
+    Continuous,
+    Transient,
+    OneTime,
 }
 
 #[derive(Clone, Debug)]
@@ -43,14 +54,13 @@ pub struct ProjectionStatus {
 #[derive(Clone)]
 pub struct ProjectionClient {
     client: GrpcClient,
-    settings: ClientSettings,
 }
 
 impl ProjectionClient {
     pub async fn new(settings: ClientSettings) -> Result<Self, Box<dyn std::error::Error>> {
-        let client = GrpcClient::create(settings.clone()).await?;
+        let client = GrpcClient::create(settings).await?;
 
-        Ok(ProjectionClient { client, settings })
+        Ok(ProjectionClient { client })
     }
 
     pub async fn create<Name>(
@@ -79,7 +89,11 @@ impl ProjectionClient {
         // TODO - create projection RPC call needs to be fixed upstream where the emit options
         // will be added to the API. Right now, do an extra RPC call to implement it.
         if options.emit {
-            let upd_options = UpdateProjectionOptions::default().emit(true);
+            let mut upd_options = UpdateProjectionOptions::default().emit(true);
+
+            if let Some(credentials) = options.credentials.clone() {
+                upd_options = upd_options.authenticated(credentials);
+            }
 
             self.update(name.as_ref(), query, &upd_options).await?;
         }
@@ -87,6 +101,29 @@ impl ProjectionClient {
         Ok(())
     }
 
+    pub async fn create_transient<Name>(
+        &self,
+        name: Name,
+        query: String,
+        options: &CreateTransientProjectionOptions,
+    ) -> crate::Result<()>
+    where
+        Name: AsRef<str>,
+    {
+        self.create_projection_internal(
+            options.credentials.as_ref(),
+            projections::create_req::Options {
+                query,
+                mode: Some(projections::create_req::options::Mode::Transient(
+                    projections::create_req::options::Transient {
+                        name: name.as_ref().to_string(),
+                    },
+                )),
+            },
+        )
+        .await
+    }
+
     async fn create_projection_internal(
         &self,
         credentials: Option<&Credentials>,
@@ -98,7 +135,12 @@ impl ProjectionClient {
 
         let mut req = tonic::Request::new(req);
 
-        crate::commands::configure_auth_req(&mut req, credentials.cloned());
+        crate::commands::configure_auth_req(
+            &mut req,
+            credentials
+                .cloned()
+                .or_else(|| self.client.default_credentials()),
+        );
 
         self.client
             .execute(|handle| async move {
@@ -139,7 +181,13 @@ impl ProjectionClient {
 
         let mut req = tonic::Request::new(req);
 
-        crate::commands::configure_auth_req(&mut req, options.credentials.as_ref().cloned());
+        crate::commands::configure_auth_req(
+            &mut req,
+            options
+                .credentials
+                .clone()
+                .or_else(|| self.client.default_credentials()),
+        );
 
         self.client
             .execute(|handle| async move {
@@ -174,7 +222,13 @@ impl ProjectionClient {
 
         let mut req = tonic::Request::new(req);
 
-        crate::commands::configure_auth_req(&mut req, options.credentials.as_ref().cloned());
+        crate::commands::configure_auth_req(
+            &mut req,
+            options
+                .credentials
+                .clone()
+                .or_else(|| self.client.default_credentials()),
+        );
 
         self.client
             .execute(|handle| async move {
@@ -210,6 +264,22 @@ impl ProjectionClient {
         self.statistics(StatsFor::AllContinuous, credentials).await
     }
 
+    /// Lists the projections of the given mode along with their statistics.
+    pub async fn list_by_mode(
+        &self,
+        mode: ProjectionListMode,
+        credentials: Option<&Credentials>,
+    ) -> crate::Result<BoxStream<'_, crate::Result<ProjectionStatus>>> {
+        let stats_for = match mode {
+            ProjectionListMode::All => StatsFor::AllProjections,
+            ProjectionListMode::Continuous => StatsFor::AllContinuous,
+            ProjectionListMode::Transient => StatsFor::AllTransient,
+            ProjectionListMode::OneTime => StatsFor::AllOneTime,
+        };
+
+        self.statistics(stats_for, credentials).await
+    }
+
     async fn statistics(
         &self,
         stats_for: StatsFor,
@@ -223,6 +293,10 @@ impl ProjectionClient {
             StatsFor::AllContinuous => {
                 projections::statistics_req::options::Mode::Continuous(Empty {})
             }
+            StatsFor::AllTransient => {
+                projections::statistics_req::options::Mode::Transient(Empty {})
+            }
+            StatsFor::AllOneTime => projections::statistics_req::options::Mode::OneTime(Empty {}),
         };
 
         let options = projections::statistics_req::Options { mode: Some(mode) };
@@ -233,7 +307,12 @@ impl ProjectionClient {
 
         let mut req = tonic::Request::new(req);
 
-        crate::commands::configure_auth_req(&mut req, credentials.cloned());
+        crate::commands::configure_auth_req(
+            &mut req,
+            credentials
+                .cloned()
+                .or_else(|| self.client.default_credentials()),
+        );
 
         self.client
             .execute(|handle| async move {
@@ -313,7 +392,12 @@ impl ProjectionClient {
 
         let mut req = tonic::Request::new(req);
 
-        crate::commands::configure_auth_req(&mut req, credentials.cloned());
+        crate::commands::configure_auth_req(
+            &mut req,
+            credentials
+                .cloned()
+                .or_else(|| self.client.default_credentials()),
+        );
 
         self.client
             .execute(|handle| async move {
@@ -346,7 +430,12 @@ impl ProjectionClient {
 
         let mut req = tonic::Request::new(req);
 
-        crate::commands::configure_auth_req(&mut req, credentials.cloned());
+        crate::commands::configure_auth_req(
+            &mut req,
+            credentials
+                .cloned()
+                .or_else(|| self.client.default_credentials()),
+        );
 
         self.client
             .execute(|handle| async move {
@@ -404,7 +493,12 @@ impl ProjectionClient {
 
         let mut req = tonic::Request::new(req);
 
-        crate::commands::configure_auth_req(&mut req, credentials.cloned());
+        crate::commands::configure_auth_req(
+            &mut req,
+            credentials
+                .cloned()
+                .or_else(|| self.client.default_credentials()),
+        );
 
         self.client
             .execute(|handle| async move {
@@ -438,7 +532,13 @@ impl ProjectionClient {
 
         let mut req = tonic::Request::new(req);
 
-        crate::commands::configure_auth_req(&mut req, options.credentials.as_ref().cloned());
+        crate::commands::configure_auth_req(
+            &mut req,
+            options
+                .credentials
+                .clone()
+                .or_else(|| self.client.default_credentials()),
+        );
 
         self.client
             .execute(|handle| async move {
@@ -476,7 +576,13 @@ impl ProjectionClient {
 
         let mut req = tonic::Request::new(req);
 
-        crate::commands::configure_auth_req(&mut req, options.credentials.as_ref().cloned());
+        crate::commands::configure_auth_req(
+            &mut req,
+            options
+                .credentials
+                .clone()
+                .or_else(|| self.client.default_credentials()),
+        );
 
         self.client
             .execute(|handle| async move {
@@ -497,7 +603,12 @@ impl ProjectionClient {
     pub async fn restart_subsystem(&self, credentials: Option<&Credentials>) -> crate::Result<()> {
         let mut req = tonic::Request::new(Empty {});
 
-        crate::commands::configure_auth_req(&mut req, credentials.cloned());
+        crate::commands::configure_auth_req(
+            &mut req,
+            credentials
+                .cloned()
+                .or_else(|| self.client.default_credentials()),
+        );
 
         self.client
             .execute(|handle| async {
@@ -2,7 +2,8 @@
 //! Common types used across the library.
 use std::cmp::Ordering;
 use std::collections::HashMap;
-use std::time::Duration;
+use std::convert::TryFrom;
+use std::time::{Duration, SystemTime};
 
 use crate::private::Sealed;
 use async_trait::async_trait;
@@ -167,7 +168,7 @@ impl Ord for Position {
 }
 
 /// Returned after writing to a stream.
-#[derive(Debug)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct WriteResult {
     /// Next expected version of the stream.
     pub next_expected_version: u64,
@@ -176,6 +177,24 @@ pub struct WriteResult {
     pub position: Position,
 }
 
+/// Returned after writing to a stream in several appends. Appends committed before a
+/// failure stay committed, so their results are kept next to it.
+#[derive(Debug)]
+pub struct ChunkedWriteResult {
+    /// Results of the committed appends, in order.
+    pub committed: Vec<WriteResult>,
+
+    /// Why the remaining appends weren't sent, if the write stopped early.
+    pub failure: Option<ChunkedWriteFailure>,
+}
+
+/// Reason a write split in several appends stopped before its last append.
+#[derive(Debug)]
+pub enum ChunkedWriteFailure {
+    WrongExpectedVersion(WrongExpectedVersion),
+    Error(Error),
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum StreamPosition<A> {
     Start,
@@ -234,6 +253,9 @@ pub struct RecordedEvent {
 
     /// An event position in the $all stream.
     pub position: Position,
+
+    /// When the server wrote this event.
+    pub created: Option<SystemTime>,
 }
 
 impl RecordedEvent {
@@ -244,6 +266,30 @@ impl RecordedEvent {
     {
         serde_json::from_slice(&self.data[..])
     }
+
+    /// Returns the `$correlationId` stored in this event's JSON custom metadata, if any.
+    pub fn correlation_id(&self) -> Option<String> {
+        self.custom_metadata_property("$correlationId")
+    }
+
+    /// Returns the `$causationId` stored in this event's JSON custom metadata, if any.
+    pub fn causation_id(&self) -> Option<String> {
+        self.custom_metadata_property("$causationId")
+    }
+
+    fn custom_metadata_property(&self, name: &str) -> Option<String> {
+        let metadata: serde_json::Map<String, serde_json::Value> =
+            serde_json::from_slice(&self.custom_metadata[..]).ok()?;
+
+        metadata.get(name)?.as_str().map(|value| value.to_string())
+    }
+}
+
+/// Converts ticks (100 nanoseconds) since the Unix epoch, the way the server expresses dates.
+pub(crate) fn from_ticks_since_epoch(ticks: i64) -> Option<SystemTime> {
+    let ticks = u64::try_from(ticks).ok()?;
+
+    SystemTime::UNIX_EPOCH.checked_add(Duration::from_nanos(ticks.saturating_mul(100)))
 }
 
 /// A structure representing a single event or an resolved link event.
@@ -299,7 +345,7 @@ pub struct VersionedMetadata {
     pub stream: String,
 
     /// Metadata's version.
-    pub version: i64,
+    pub version: u64,
 
     /// Metadata properties.
     pub metadata: StreamMetadata,
@@ -379,6 +425,15 @@ impl EventData {
         }
     }
 
+    /// Creates a link event pointing to `target`. Once appended to a stream, reading that stream
+    /// with link resolution enabled returns `target` in place of the link, which is how custom
+    /// index streams are built.
+    pub fn link(target: &RecordedEvent) -> Self {
+        let payload = Bytes::from(format!("{}@{}", target.revision, target.stream_id));
+
+        EventData::binary("$>", payload)
+    }
+
     /// Set an id to this event. By default, the id will be generated
     pub fn id(self, value: Uuid) -> Self {
         EventData {
@@ -406,6 +461,62 @@ impl EventData {
             ..self
         }
     }
+
+    /// Marks this event as caused by `event`, following EventStoreDB conventions: `$causationId`
+    /// is set to the id of `event` and `$correlationId` is carried over from it, or set to its id
+    /// when `event` has none. Both are merged into the JSON custom metadata of this event, which
+    /// must be a JSON object if already set.
+    pub fn caused_by(self, event: &RecordedEvent) -> serde_json::Result<EventData> {
+        let mut metadata: serde_json::Map<String, serde_json::Value> =
+            match self.custom_metadata.as_ref() {
+                Some(bytes) if !bytes.is_empty() => serde_json::from_slice(&bytes[..])?,
+                _ => serde_json::Map::new(),
+            };
+
+        let correlation_id = event
+            .correlation_id()
+            .unwrap_or_else(|| event.id.to_string());
+
+        metadata.insert("$correlationId".to_string(), correlation_id.into());
+        metadata.insert("$causationId".to_string(), event.id.to_string().into());
+
+        self.metadata_as_json(metadata)
+    }
+
+    /// Approximate size in bytes this event takes once sent to the server.
+    pub(crate) fn approximate_size(&self) -> usize {
+        let metadata_size: usize = self.metadata.iter().map(|(k, v)| k.len() + v.len()).sum();
+        let custom_metadata_size = self.custom_metadata.as_ref().map_or(0, |m| m.len());
+
+        // The event id takes 16 bytes.
+        self.payload.len() + metadata_size + custom_metadata_size + 16
+    }
+}
+
+/// Splits events into consecutive chunks whose approximate size doesn't exceed `max_size`. An
+/// event bigger than `max_size` ends up alone in its own chunk.
+pub(crate) fn chunk_events(events: Vec<EventData>, max_size: usize) -> Vec<Vec<EventData>> {
+    let mut chunks = Vec::new();
+    let mut current = Vec::new();
+    let mut current_size = 0usize;
+
+    for event in events {
+        let size = event.approximate_size();
+
+        if !current.is_empty() && current_size + size > max_size {
+            chunks.push(std::mem::take(&mut current));
+            current_size = 0;
+        }
+
+        current_size += size;
+        current.push(event);
+    }
+
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+
+    chunks
 }
 
 /// Used to facilitate the creation of a stream's metadata.
@@ -794,6 +905,21 @@ pub struct StreamAcl {
     pub meta_write_roles: Option<Vec<String>>,
 }
 
+impl StreamAcl {
+    /// Merges `other` on top of this ACL. Every role list set in `other`
+    /// replaces the matching list of this ACL, while lists left unset in
+    /// `other` are kept as they are.
+    pub fn merge(self, other: StreamAcl) -> StreamAcl {
+        StreamAcl {
+            read_roles: other.read_roles.or(self.read_roles),
+            write_roles: other.write_roles.or(self.write_roles),
+            delete_roles: other.delete_roles.or(self.delete_roles),
+            meta_read_roles: other.meta_read_roles.or(self.meta_read_roles),
+            meta_write_roles: other.meta_write_roles.or(self.meta_write_roles),
+        }
+    }
+}
+
 fn serialize_roles<S>(
     src: &Option<Vec<String>>,
     serializer: S,
@@ -975,6 +1101,128 @@ mod metadata_tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_stream_acl_merge() {
+        let base = StreamAclBuilder::new()
+            .add_read_roles("ops")
+            .add_write_roles("admin")
+            .build();
+
+        let overrides = StreamAclBuilder::new()
+            .add_read_roles("$all")
+            .add_delete_roles("admin")
+            .build();
+
+        let expected = StreamAclBuilder::new()
+            .add_read_roles("$all")
+            .add_write_roles("admin")
+            .add_delete_roles("admin")
+            .build();
+
+        assert_eq!(expected, base.merge(overrides));
+    }
+}
+
+#[cfg(test)]
+mod test_fixtures {
+    use super::{EventData, Position, RecordedEvent};
+    use std::collections::HashMap;
+    use uuid::Uuid;
+
+    /// Builds the event the server would record for `event` at revision 0 of stream `foo`.
+    pub(super) fn recorded(event: EventData) -> RecordedEvent {
+        RecordedEvent {
+            stream_id: "foo".to_string(),
+            id: event.id_opt.unwrap_or_else(Uuid::new_v4),
+            revision: 0,
+            event_type: "foo".to_string(),
+            data: event.payload,
+            metadata: HashMap::new(),
+            custom_metadata: event.custom_metadata.unwrap_or_default(),
+            is_json: true,
+            position: Position::start(),
+            created: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod correlation_tests {
+    use super::test_fixtures::recorded;
+    use super::EventData;
+    use bytes::Bytes;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_correlation_chain() -> serde_json::Result<()> {
+        let command = recorded(EventData::binary("command", Bytes::new()).id(Uuid::new_v4()));
+
+        let first = EventData::binary("first", Bytes::new())
+            .id(Uuid::new_v4())
+            .metadata_as_json(serde_json::json!({ "foo": "bar" }))?
+            .caused_by(&command)?;
+        let first = recorded(first);
+
+        assert_eq!(Some(command.id.to_string()), first.correlation_id());
+        assert_eq!(Some(command.id.to_string()), first.causation_id());
+
+        let second = recorded(EventData::binary("second", Bytes::new()).caused_by(&first)?);
+
+        assert_eq!(Some(command.id.to_string()), second.correlation_id());
+        assert_eq!(Some(first.id.to_string()), second.causation_id());
+
+        let metadata: serde_json::Value = serde_json::from_slice(&first.custom_metadata[..])?;
+        assert_eq!(Some("bar"), metadata["foo"].as_str());
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod link_tests {
+    use super::test_fixtures::recorded;
+    use super::{EventData, RecordedEvent};
+    use bytes::Bytes;
+
+    #[test]
+    fn test_link_event() {
+        let target = RecordedEvent {
+            stream_id: "orders".to_string(),
+            revision: 42,
+            ..recorded(EventData::binary("order-placed", Bytes::new()))
+        };
+        let link = EventData::link(&target);
+
+        assert_eq!(Some("$>"), link.metadata.get("type").map(|t| t.as_str()));
+        assert_eq!(Bytes::from("42@orders"), link.payload);
+    }
+}
+
+#[cfg(test)]
+mod chunk_tests {
+    use super::{chunk_events, EventData};
+    use bytes::Bytes;
+
+    #[test]
+    fn test_chunk_events() {
+        let events = (0..5)
+            .map(|_| EventData::binary("foo", Bytes::from(vec![0u8; 100])))
+            .collect::<Vec<_>>();
+
+        let size = events[0].approximate_size();
+        let chunks = chunk_events(events, size * 2);
+
+        assert_eq!(
+            vec![2, 2, 1],
+            chunks.iter().map(|c| c.len()).collect::<Vec<_>>()
+        );
+
+        let big = EventData::binary("foo", Bytes::from(vec![0u8; 1_000]));
+        let chunks = chunk_events(vec![big], size);
+
+        assert_eq!(1, chunks.len());
+    }
 }
 
 /// Read part of a persistent subscription, isomorphic to a stream of events.
@@ -1108,8 +1356,8 @@ pub struct PersistentSubscriptionSettings {
     pub named_consumer_strategy: SystemConsumerStrategy,
 }
 
-impl PersistentSubscriptionSettings {
-    pub fn default() -> PersistentSubscriptionSettings {
+impl Default for PersistentSubscriptionSettings {
+    fn default() -> PersistentSubscriptionSettings {
         PersistentSubscriptionSettings {
             resolve_link_tos: false,
             revision: 0,
@@ -1128,12 +1376,6 @@ impl PersistentSubscriptionSettings {
     }
 }
 
-impl Default for PersistentSubscriptionSettings {
-    fn default() -> PersistentSubscriptionSettings {
-        PersistentSubscriptionSettings::default()
-    }
-}
-
 /// Represents the different scenarios that could happen when performing
 /// a persistent subscription.
 #[derive(Debug, Eq, PartialEq)]
@@ -1174,7 +1416,7 @@ pub enum PersistActionError {
 }
 
 /// Indicates which order of preferred nodes for connecting to.
-#[derive(Copy, Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default, Serialize, Deserialize)]
 pub enum NodePreference {
     /// When attempting connection, prefers leader nodes.
     Leader,
@@ -1183,18 +1425,13 @@ pub enum NodePreference {
     Follower,
 
     /// When attempting connection, has no node preference.
+    #[default]
     Random,
 
     /// When attempting connection, prefers read-replica nodes.
     ReadOnlyReplica,
 }
 
-impl Default for NodePreference {
-    fn default() -> Self {
-        NodePreference::Random
-    }
-}
-
 impl std::fmt::Display for NodePreference {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         use self::NodePreference::*;
@@ -1228,6 +1465,19 @@ pub(crate) struct DnsClusterSettings {
     pub(crate) endpoint: Endpoint,
 }
 
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+/// State of a stream, as probed by `Client::stream_state`.
+pub enum StreamState {
+    /// The stream doesn't exist.
+    NoStream,
+
+    /// The stream has been hard deleted.
+    Deleted,
+
+    /// The stream exists, the last event's number is given.
+    Exists(u64),
+}
+
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 /// Actual revision of a stream.
 pub enum CurrentRevision {
@@ -1283,10 +1533,18 @@ pub enum Error {
     ResourceAlreadyExists,
     #[error("The resource you asked for doesn't exist")]
     ResourceNotFound,
+    #[error("Stream {0} has been deleted")]
+    StreamDeleted(String),
+    #[error("The operation didn't complete before its deadline")]
+    DeadlineExceeded,
     #[error("Unexpected internal client error. Please fill an issue on GitHub")]
     InternalClientError,
 }
 
+/// Message of the status tonic returns when a request timeout expires before the server
+/// answered.
+const CLIENT_TIMEOUT_EXPIRED_MESSAGE: &str = "Timeout expired";
+
 impl Error {
     pub fn from_grpc(status: Status) -> Self {
         let metadata = status.metadata();
@@ -1308,6 +1566,16 @@ impl Error {
             }
         }
 
+        if let Some("stream-deleted") = metadata.get("exception").and_then(|e| e.to_str().ok()) {
+            let stream = metadata
+                .get("stream-name")
+                .and_then(|s| s.to_str().ok())
+                .unwrap_or_default()
+                .to_string();
+
+            return Error::StreamDeleted(stream);
+        }
+
         if status.code() == Code::Unauthenticated || status.code() == Code::PermissionDenied {
             return Error::AccessDenied;
         }
@@ -1320,6 +1588,14 @@ impl Error {
             return Error::ResourceNotFound;
         }
 
+        // tonic reports a request timeout expiring on the client side as a cancellation.
+        if status.code() == Code::DeadlineExceeded
+            || (status.code() == Code::Cancelled
+                && status.message() == CLIENT_TIMEOUT_EXPIRED_MESSAGE)
+        {
+            return Error::DeadlineExceeded;
+        }
+
         if status.code() == Code::Unavailable
             || status.code() == Code::Internal
             || status.code() == Code::DataLoss
@@ -1331,6 +1607,28 @@ impl Error {
     }
 }
 
+#[cfg(test)]
+mod error_tests {
+    use super::Error;
+    use tonic::Status;
+
+    #[test]
+    fn test_deadline_exceeded_from_grpc() {
+        assert!(matches!(
+            Error::from_grpc(Status::deadline_exceeded("Deadline Exceeded")),
+            Error::DeadlineExceeded
+        ));
+        assert!(matches!(
+            Error::from_grpc(Status::cancelled("Timeout expired")),
+            Error::DeadlineExceeded
+        ));
+        assert!(matches!(
+            Error::from_grpc(Status::cancelled("Cancelled by the server")),
+            Error::Grpc(_)
+        ));
+    }
+}
+
 #[derive(Error, Debug, Clone)]
 /// EventStoreDB command error.
 pub enum GrpcConnectionError {
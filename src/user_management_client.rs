@@ -0,0 +1,365 @@
+use crate::event_store::client::users;
+use crate::grpc::{ClientSettings, GrpcClient};
+use crate::Credentials;
+use futures::stream::BoxStream;
+use std::time::SystemTime;
+
+#[derive(Clone, Debug)]
+pub struct UserDetails {
+    pub login_name: String,
+    pub full_name: String,
+    pub groups: Vec<String>,
+    pub last_updated: Option<SystemTime>,
+    pub disabled: bool,
+}
+
+impl From<users::details_resp::UserDetails> for UserDetails {
+    fn from(details: users::details_resp::UserDetails) -> Self {
+        let last_updated = details
+            .last_updated
+            .and_then(|date| crate::types::from_ticks_since_epoch(date.ticks_since_epoch));
+
+        UserDetails {
+            login_name: details.login_name,
+            full_name: details.full_name,
+            groups: details.groups,
+            last_updated,
+            disabled: details.disabled,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct UserManagementClient {
+    client: GrpcClient,
+}
+
+impl UserManagementClient {
+    pub async fn new(settings: ClientSettings) -> Result<Self, Box<dyn std::error::Error>> {
+        let client = GrpcClient::create(settings).await?;
+
+        Ok(UserManagementClient { client })
+    }
+
+    fn request<A>(&self, message: A, credentials: Option<&Credentials>) -> tonic::Request<A> {
+        let mut req = tonic::Request::new(message);
+
+        crate::commands::configure_auth_req(
+            &mut req,
+            credentials
+                .cloned()
+                .or_else(|| self.client.default_credentials()),
+        );
+
+        req
+    }
+
+    pub async fn create<Login>(
+        &self,
+        login_name: Login,
+        password: String,
+        full_name: String,
+        groups: Vec<String>,
+        credentials: Option<&Credentials>,
+    ) -> crate::Result<()>
+    where
+        Login: AsRef<str>,
+    {
+        let options = users::create_req::Options {
+            login_name: login_name.as_ref().to_string(),
+            password,
+            full_name,
+            groups,
+        };
+
+        let req = self.request(
+            users::CreateReq {
+                options: Some(options),
+            },
+            credentials,
+        );
+
+        self.client
+            .execute(|handle| async move {
+                let mut client = users::users_client::UsersClient::new(handle.channel);
+                let _ = client.create(req).await?;
+
+                Ok(())
+            })
+            .await
+    }
+
+    pub async fn update<Login>(
+        &self,
+        login_name: Login,
+        password: String,
+        full_name: String,
+        groups: Vec<String>,
+        credentials: Option<&Credentials>,
+    ) -> crate::Result<()>
+    where
+        Login: AsRef<str>,
+    {
+        let options = users::update_req::Options {
+            login_name: login_name.as_ref().to_string(),
+            password,
+            full_name,
+            groups,
+        };
+
+        let req = self.request(
+            users::UpdateReq {
+                options: Some(options),
+            },
+            credentials,
+        );
+
+        self.client
+            .execute(|handle| async move {
+                let mut client = users::users_client::UsersClient::new(handle.channel);
+                let _ = client.update(req).await?;
+
+                Ok(())
+            })
+            .await
+    }
+
+    pub async fn delete<Login>(
+        &self,
+        login_name: Login,
+        credentials: Option<&Credentials>,
+    ) -> crate::Result<()>
+    where
+        Login: AsRef<str>,
+    {
+        let options = users::delete_req::Options {
+            login_name: login_name.as_ref().to_string(),
+        };
+
+        let req = self.request(
+            users::DeleteReq {
+                options: Some(options),
+            },
+            credentials,
+        );
+
+        self.client
+            .execute(|handle| async move {
+                let mut client = users::users_client::UsersClient::new(handle.channel);
+                let _ = client.delete(req).await?;
+
+                Ok(())
+            })
+            .await
+    }
+
+    pub async fn enable<Login>(
+        &self,
+        login_name: Login,
+        credentials: Option<&Credentials>,
+    ) -> crate::Result<()>
+    where
+        Login: AsRef<str>,
+    {
+        let options = users::enable_req::Options {
+            login_name: login_name.as_ref().to_string(),
+        };
+
+        let req = self.request(
+            users::EnableReq {
+                options: Some(options),
+            },
+            credentials,
+        );
+
+        self.client
+            .execute(|handle| async move {
+                let mut client = users::users_client::UsersClient::new(handle.channel);
+                let _ = client.enable(req).await?;
+
+                Ok(())
+            })
+            .await
+    }
+
+    pub async fn disable<Login>(
+        &self,
+        login_name: Login,
+        credentials: Option<&Credentials>,
+    ) -> crate::Result<()>
+    where
+        Login: AsRef<str>,
+    {
+        let options = users::disable_req::Options {
+            login_name: login_name.as_ref().to_string(),
+        };
+
+        let req = self.request(
+            users::DisableReq {
+                options: Some(options),
+            },
+            credentials,
+        );
+
+        self.client
+            .execute(|handle| async move {
+                let mut client = users::users_client::UsersClient::new(handle.channel);
+                let _ = client.disable(req).await?;
+
+                Ok(())
+            })
+            .await
+    }
+
+    pub async fn get<Login>(
+        &self,
+        login_name: Login,
+        credentials: Option<&Credentials>,
+    ) -> crate::Result<Option<UserDetails>>
+    where
+        Login: AsRef<str>,
+    {
+        use futures::TryStreamExt;
+
+        // The server answers `NotFound` for an unknown user, either right away or on the stream.
+        let mut stream = match self
+            .details(login_name.as_ref().to_string(), credentials)
+            .await
+        {
+            Err(crate::Error::ResourceNotFound) => return Ok(None),
+            result => result?,
+        };
+
+        match stream.try_next().await {
+            Err(crate::Error::ResourceNotFound) => Ok(None),
+            result => result,
+        }
+    }
+
+    pub async fn list(
+        &self,
+        credentials: Option<&Credentials>,
+    ) -> crate::Result<BoxStream<'_, crate::Result<UserDetails>>> {
+        // An empty login name asks the server for every user.
+        self.details(String::new(), credentials).await
+    }
+
+    async fn details(
+        &self,
+        login_name: String,
+        credentials: Option<&Credentials>,
+    ) -> crate::Result<BoxStream<'_, crate::Result<UserDetails>>> {
+        use futures::TryStreamExt;
+
+        let options = users::details_req::Options { login_name };
+
+        let req = self.request(
+            users::DetailsReq {
+                options: Some(options),
+            },
+            credentials,
+        );
+
+        self.client
+            .execute(|handle| async move {
+                let mut client = users::users_client::UsersClient::new(handle.channel.clone());
+
+                let mut stream = client.details(req).await?.into_inner();
+
+                let stream = async_stream::stream! {
+                    loop {
+                        match stream.try_next().await {
+                            Err(e) => {
+                                let e = crate::Error::from_grpc(e);
+
+                                handle.report_error(e.clone()).await;
+                                yield Err(e);
+                                break;
+                            }
+
+                            Ok(resp) => {
+                                if let Some(resp) = resp {
+                                    if let Some(details) = resp.user_details {
+                                        yield Ok(details.into());
+                                    }
+
+                                    continue;
+                                }
+
+                                break;
+                            }
+                        }
+                    }
+                };
+
+                let stream: BoxStream<crate::Result<UserDetails>> = Box::pin(stream);
+
+                Ok(stream)
+            })
+            .await
+    }
+
+    pub async fn change_password<Login>(
+        &self,
+        login_name: Login,
+        current_password: String,
+        new_password: String,
+        credentials: Option<&Credentials>,
+    ) -> crate::Result<()>
+    where
+        Login: AsRef<str>,
+    {
+        let options = users::change_password_req::Options {
+            login_name: login_name.as_ref().to_string(),
+            current_password,
+            new_password,
+        };
+
+        let req = self.request(
+            users::ChangePasswordReq {
+                options: Some(options),
+            },
+            credentials,
+        );
+
+        self.client
+            .execute(|handle| async move {
+                let mut client = users::users_client::UsersClient::new(handle.channel);
+                let _ = client.change_password(req).await?;
+
+                Ok(())
+            })
+            .await
+    }
+
+    pub async fn reset_password<Login>(
+        &self,
+        login_name: Login,
+        new_password: String,
+        credentials: Option<&Credentials>,
+    ) -> crate::Result<()>
+    where
+        Login: AsRef<str>,
+    {
+        let options = users::reset_password_req::Options {
+            login_name: login_name.as_ref().to_string(),
+            new_password,
+        };
+
+        let req = self.request(
+            users::ResetPasswordReq {
+                options: Some(options),
+            },
+            credentials,
+        );
+
+        self.client
+            .execute(|handle| async move {
+                let mut client = users::users_client::UsersClient::new(handle.channel);
+                let _ = client.reset_password(req).await?;
+
+                Ok(())
+            })
+            .await
+    }
+}
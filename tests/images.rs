@@ -1,4 +1,5 @@
 #![allow(dead_code)]
+#![allow(clippy::upper_case_acronyms)]
 
 use std::collections::HashMap;
 use testcontainers::{Container, Docker, Image, WaitForMessage};
@@ -92,7 +93,7 @@ impl Default for ESDB {
     fn default() -> Self {
         ESDB {
             tag: DEFAULT_TAG.to_string(),
-            arguments: ESDBArgs::default(),
+            arguments: ESDBArgs,
             env_vars: HashMap::new(),
             vol_vars: HashMap::new(),
         }